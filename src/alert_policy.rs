@@ -0,0 +1,121 @@
+use std::time::{Duration, SystemTime};
+
+use crate::website::Severity;
+
+/// Controls when a string of failed checks actually triggers a notification,
+/// instead of alerting on every single failed probe.
+#[derive(Debug, Clone)]
+pub struct AlertPolicy {
+    /// Consecutive failures required before the first alert fires.
+    pub failure_threshold: u32,
+    /// Consecutive failures required before alerts escalate to `Emergency`.
+    pub emergency_threshold: u32,
+    /// Minimum time between repeat alerts for the same website.
+    pub resend_period: Duration,
+}
+
+impl Default for AlertPolicy {
+    fn default() -> Self {
+        AlertPolicy {
+            failure_threshold: 2,
+            emergency_threshold: 10,
+            resend_period: Duration::from_secs(2 * 60 * 60),
+        }
+    }
+}
+
+impl AlertPolicy {
+    /// Decides whether a new alert should go out for a site that has now
+    /// failed `consecutive_failures` times in a row, given when and at what
+    /// severity the last alert for that site was sent. Returns the severity
+    /// to notify at, or `None` if this failure should stay quiet.
+    ///
+    /// An escalation to `Severity::Emergency` always fires, even if the
+    /// resend period hasn't elapsed since the last `Warning` - otherwise the
+    /// throttle meant to silence duplicate warnings could just as easily
+    /// silence the escalation itself.
+    pub fn decide_notification(
+        &self,
+        consecutive_failures: u32,
+        last_notified: Option<SystemTime>,
+        last_notified_severity: Option<Severity>,
+        now: SystemTime,
+    ) -> Option<Severity> {
+        if consecutive_failures < self.failure_threshold {
+            return None;
+        }
+
+        let severity = if consecutive_failures >= self.emergency_threshold {
+            Severity::Emergency
+        } else {
+            Severity::Warning
+        };
+
+        let escalated = matches!(
+            (last_notified_severity, severity),
+            (Some(Severity::Warning), Severity::Emergency)
+        );
+
+        let resend_elapsed = last_notified.map_or(true, |last| {
+            now.duration_since(last)
+                .map(|elapsed| elapsed >= self.resend_period)
+                .unwrap_or(true)
+        });
+
+        (escalated || resend_elapsed).then_some(severity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy() -> AlertPolicy {
+        AlertPolicy {
+            failure_threshold: 2,
+            emergency_threshold: 4,
+            resend_period: Duration::from_secs(3600),
+        }
+    }
+
+    #[test]
+    fn stays_quiet_below_the_failure_threshold() {
+        let decision = policy().decide_notification(1, None, None, SystemTime::now());
+        assert_eq!(decision, None);
+    }
+
+    #[test]
+    fn first_alert_at_the_failure_threshold_is_a_warning() {
+        let decision = policy().decide_notification(2, None, None, SystemTime::now());
+        assert_eq!(decision, Some(Severity::Warning));
+    }
+
+    #[test]
+    fn repeat_warning_is_throttled_until_resend_period_elapses() {
+        let now = SystemTime::now();
+        let decision = policy().decide_notification(3, Some(now), Some(Severity::Warning), now);
+        assert_eq!(decision, None);
+    }
+
+    #[test]
+    fn repeat_warning_fires_again_once_resend_period_elapses() {
+        let last = SystemTime::now() - Duration::from_secs(7200);
+        let decision =
+            policy().decide_notification(3, Some(last), Some(Severity::Warning), SystemTime::now());
+        assert_eq!(decision, Some(Severity::Warning));
+    }
+
+    #[test]
+    fn escalation_to_emergency_bypasses_the_resend_throttle() {
+        let now = SystemTime::now();
+        let decision = policy().decide_notification(4, Some(now), Some(Severity::Warning), now);
+        assert_eq!(decision, Some(Severity::Emergency));
+    }
+
+    #[test]
+    fn repeat_emergency_is_still_throttled() {
+        let now = SystemTime::now();
+        let decision = policy().decide_notification(5, Some(now), Some(Severity::Emergency), now);
+        assert_eq!(decision, None);
+    }
+}
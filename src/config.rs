@@ -0,0 +1,255 @@
+use serde::Deserialize;
+use std::error::Error;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::alert_policy::AlertPolicy;
+use crate::monitor::WebsiteMonitor;
+use crate::notifier::NotifierConfig;
+use crate::website::{ExpectedStatus, WebsiteOptions};
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct WebsiteEntry {
+    pub url: String,
+    #[serde(default)]
+    pub timeout_secs: Option<u64>,
+    #[serde(default)]
+    pub expected_status: Option<ExpectedStatus>,
+    #[serde(default)]
+    pub check_interval_secs: Option<u64>,
+    #[serde(default)]
+    pub body_must_contain: Option<String>,
+}
+
+impl WebsiteEntry {
+    fn options(&self) -> WebsiteOptions {
+        WebsiteOptions {
+            timeout_secs: self.timeout_secs,
+            expected_status: self.expected_status.clone(),
+            check_interval_secs: self.check_interval_secs,
+            body_must_contain: self.body_must_contain.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AlertPolicyConfig {
+    pub failure_threshold: u32,
+    pub emergency_threshold: u32,
+    pub resend_period_secs: u64,
+}
+
+impl From<AlertPolicyConfig> for AlertPolicy {
+    fn from(config: AlertPolicyConfig) -> Self {
+        AlertPolicy {
+            failure_threshold: config.failure_threshold,
+            emergency_threshold: config.emergency_threshold,
+            resend_period: Duration::from_secs(config.resend_period_secs),
+        }
+    }
+}
+
+/// The full, on-disk shape of a pingu deployment: the websites to watch,
+/// where to send alerts, and how aggressively to alert.
+#[derive(Debug, Clone, Deserialize)]
+pub struct MonitorConfig {
+    pub websites: Vec<WebsiteEntry>,
+    #[serde(default)]
+    pub notifiers: Vec<NotifierConfig>,
+    pub alert_policy: Option<AlertPolicyConfig>,
+}
+
+/// Parses a `MonitorConfig` from a `.ron` or `.toml` file, picked by the
+/// file's extension.
+pub fn load_monitor_config(path: &Path) -> Result<MonitorConfig, Box<dyn Error>> {
+    let contents = std::fs::read_to_string(path)?;
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("ron") => Ok(ron::from_str(&contents)?),
+        Some("toml") => Ok(toml::from_str(&contents)?),
+        other => Err(format!("unsupported config file extension: {other:?}").into()),
+    }
+}
+
+impl WebsiteMonitor {
+    /// Builds a monitor from a RON or TOML config file instead of calling
+    /// `new`/`add_website` by hand.
+    pub async fn from_config_file(path: impl AsRef<Path>) -> Result<Self, Box<dyn Error>> {
+        let config = load_monitor_config(path.as_ref())?;
+
+        let notifiers = config
+            .notifiers
+            .into_iter()
+            .map(NotifierConfig::build)
+            .collect();
+        let alert_policy = config
+            .alert_policy
+            .map(AlertPolicy::from)
+            .unwrap_or_default();
+
+        let monitor = WebsiteMonitor::new(notifiers, alert_policy);
+        for website in config.websites {
+            let options = website.options();
+            monitor.add_website_with_options(website.url, options).await;
+        }
+
+        Ok(monitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn write_temp_config(extension: &str, contents: &str) -> std::path::PathBuf {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let path = std::env::temp_dir().join(format!("pingu-test-config-{nanos}.{extension}"));
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn loads_ron_config() {
+        let path = write_temp_config(
+            "ron",
+            r#"(
+                websites: [
+                    (url: "https://example.com", check_interval_secs: Some(30)),
+                ],
+                notifiers: [],
+                alert_policy: Some((
+                    failure_threshold: 2,
+                    emergency_threshold: 10,
+                    resend_period_secs: 7200,
+                )),
+            )"#,
+        );
+
+        let config = load_monitor_config(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(config.websites.len(), 1);
+        assert_eq!(config.websites[0].url, "https://example.com");
+        assert_eq!(config.websites[0].check_interval_secs, Some(30));
+        assert_eq!(config.alert_policy.unwrap().emergency_threshold, 10);
+    }
+
+    #[test]
+    fn loads_toml_config() {
+        let path = write_temp_config(
+            "toml",
+            r#"
+            [[websites]]
+            url = "https://example.com"
+            timeout_secs = 5
+
+            [alert_policy]
+            failure_threshold = 3
+            emergency_threshold = 8
+            resend_period_secs = 60
+            "#,
+        );
+
+        let config = load_monitor_config(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(config.websites.len(), 1);
+        assert_eq!(config.websites[0].timeout_secs, Some(5));
+        assert_eq!(config.alert_policy.unwrap().failure_threshold, 3);
+    }
+
+    #[test]
+    fn rejects_unsupported_extension() {
+        let path = write_temp_config("yaml", "websites: []");
+        let result = load_monitor_config(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(result.is_err());
+    }
+}
+
+#[cfg(feature = "config-watch")]
+mod watch {
+    use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+    use std::collections::HashSet;
+    use std::error::Error;
+    use std::path::{Path, PathBuf};
+
+    use super::load_monitor_config;
+    use crate::monitor::WebsiteMonitor;
+
+    impl WebsiteMonitor {
+        /// Watches `path` for changes and reconciles the live website set
+        /// against the file on every edit, without restarting the process.
+        pub fn watch_config_file(&self, path: impl Into<PathBuf>) {
+            let path = path.into();
+            let monitor = self.clone();
+            let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+
+            std::thread::spawn({
+                let path = path.clone();
+                move || {
+                    let (watcher_tx, watcher_rx) = std::sync::mpsc::channel();
+                    let mut watcher = match notify::recommended_watcher(watcher_tx) {
+                        Ok(watcher) => watcher,
+                        Err(err) => {
+                            eprintln!("failed to start config watcher: {err:?}");
+                            return;
+                        }
+                    };
+                    if let Err(err) = watcher.watch(&path, RecursiveMode::NonRecursive) {
+                        eprintln!("failed to watch {path:?}: {err:?}");
+                        return;
+                    }
+                    for res in watcher_rx {
+                        if tx.send(res).is_err() {
+                            break;
+                        }
+                    }
+                }
+            });
+
+            tokio::spawn(async move {
+                while let Some(res) = rx.recv().await {
+                    match res {
+                        Ok(event) if event.kind.is_modify() => {
+                            if let Err(err) = monitor.reload_from_file(&path).await {
+                                eprintln!("failed to reload {path:?}: {err:?}");
+                            }
+                        }
+                        Ok(_) => {}
+                        Err(err) => eprintln!("config watch error: {err:?}"),
+                    }
+                }
+            });
+        }
+
+        async fn reload_from_file(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+            let config = load_monitor_config(path)?;
+            let live = self.websites().await;
+
+            let desired_urls: HashSet<String> =
+                config.websites.iter().map(|w| w.url.clone()).collect();
+
+            for url in live.keys() {
+                if !desired_urls.contains(url) {
+                    self.remove_website(url).await;
+                }
+            }
+
+            for website in config.websites {
+                let options = website.options();
+                if live.contains_key(&website.url) {
+                    self.update_website_options(&website.url, options).await;
+                } else {
+                    self.add_website_with_options(website.url, options).await;
+                }
+            }
+
+            Ok(())
+        }
+    }
+}
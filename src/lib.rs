@@ -1,13 +1,18 @@
+pub mod alert_policy;
+pub mod config;
+pub mod metrics;
 pub mod monitor;
+pub mod notifier;
 pub mod website;
 
 #[cfg(test)]
 mod tests {
+    use crate::alert_policy::AlertPolicy;
     use crate::monitor::WebsiteMonitor;
 
     #[tokio::test]
     async fn test_add_website() {
-        let monitor = WebsiteMonitor::default();
+        let monitor = WebsiteMonitor::new(vec![], AlertPolicy::default());
         monitor
             .add_website("https://www.example.com".to_string())
             .await;
@@ -16,7 +21,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_check_website() {
-        let monitor = WebsiteMonitor::default();
+        let monitor = WebsiteMonitor::new(vec![], AlertPolicy::default());
         let result = monitor.check_website("https://www.example.com").await;
         assert!(result.is_up());
     }
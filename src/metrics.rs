@@ -0,0 +1,155 @@
+use std::time::{Duration, SystemTime};
+
+use crate::monitor::WebsiteMonitor;
+use crate::website::{CheckStatus, Incident};
+
+impl WebsiteMonitor {
+    /// Fraction of recorded checks for `url` that came back up, in `[0, 1]`.
+    /// Returns `None` if the site isn't tracked or has no checks yet.
+    pub async fn uptime_ratio(&self, url: &str) -> Option<f64> {
+        let websites = self.websites().await;
+        let website = websites.get(url)?;
+        if website.total_checks.is_empty() {
+            return None;
+        }
+        Some(website.successful_checks as f64 / website.total_checks.len() as f64)
+    }
+
+    /// Average response time of every recorded successful check, across all
+    /// websites. Returns `None` if no check has ever succeeded.
+    pub async fn average_response_time(&self) -> Option<Duration> {
+        let websites = self.websites().await;
+        let response_times: Vec<Duration> = websites
+            .values()
+            .flat_map(|website| website.total_checks.iter())
+            .filter_map(|check| match &check.status {
+                CheckStatus::Up(details) => Some(details.response_time),
+                CheckStatus::Down { .. } => None,
+            })
+            .collect();
+
+        if response_times.is_empty() {
+            return None;
+        }
+        let total: Duration = response_times.iter().sum();
+        Some(total / response_times.len() as u32)
+    }
+
+    /// All recorded down checks at or after `since`, across every website.
+    pub async fn incidents_since(&self, since: SystemTime) -> Vec<Incident> {
+        let websites = self.websites().await;
+        websites
+            .values()
+            .flat_map(|website| {
+                let url = website.url.clone();
+                website.total_checks.iter().filter_map(move |check| {
+                    if check.timestamp < since {
+                        return None;
+                    }
+                    match &check.status {
+                        CheckStatus::Down {
+                            status_code,
+                            error_message,
+                        } => Some(Incident {
+                            url: url.clone(),
+                            status_code: *status_code,
+                            error_message: error_message.clone(),
+                            timestamp: check.timestamp,
+                        }),
+                        CheckStatus::Up(_) => None,
+                    }
+                })
+            })
+            .collect()
+    }
+
+    /// Serializes the full monitor state (same shape as `get_status`) to
+    /// JSON, for scraping by external tooling.
+    pub async fn status_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(&self.get_status().await)
+    }
+
+    /// Renders a Prometheus text-exposition snapshot of per-website gauges.
+    pub async fn status_prometheus(&self) -> String {
+        let websites = self.get_status().await;
+        let mut out = String::new();
+
+        out.push_str("# HELP pingu_up Whether the website is currently up (1) or down (0).\n");
+        out.push_str("# TYPE pingu_up gauge\n");
+        for website in &websites {
+            out.push_str(&format!(
+                "pingu_up{{url=\"{}\"}} {}\n",
+                website.url, website.is_up as u8
+            ));
+        }
+
+        out.push_str("# HELP pingu_total_checks Total number of checks performed.\n");
+        out.push_str("# TYPE pingu_total_checks counter\n");
+        for website in &websites {
+            out.push_str(&format!(
+                "pingu_total_checks{{url=\"{}\"}} {}\n",
+                website.url,
+                website.total_checks.len()
+            ));
+        }
+
+        out.push_str("# HELP pingu_successful_checks Total number of successful checks.\n");
+        out.push_str("# TYPE pingu_successful_checks counter\n");
+        for website in &websites {
+            out.push_str(&format!(
+                "pingu_successful_checks{{url=\"{}\"}} {}\n",
+                website.url, website.successful_checks
+            ));
+        }
+
+        out.push_str("# HELP pingu_consecutive_failures Current consecutive failure streak.\n");
+        out.push_str("# TYPE pingu_consecutive_failures gauge\n");
+        for website in &websites {
+            out.push_str(&format!(
+                "pingu_consecutive_failures{{url=\"{}\"}} {}\n",
+                website.url, website.consecutive_failures
+            ));
+        }
+
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::alert_policy::AlertPolicy;
+    use crate::monitor::WebsiteMonitor;
+
+    #[tokio::test]
+    async fn uptime_and_response_time_reflect_a_successful_check() {
+        let monitor = WebsiteMonitor::new(vec![], AlertPolicy::default());
+        let url = "https://www.example.com";
+        monitor.add_website(url.to_string()).await;
+        monitor.update_website_status().await;
+
+        assert_eq!(monitor.uptime_ratio(url).await, Some(1.0));
+        assert!(monitor.average_response_time().await.is_some());
+        assert!(monitor
+            .incidents_since(std::time::UNIX_EPOCH)
+            .await
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn uptime_ratio_is_none_for_an_unknown_site() {
+        let monitor = WebsiteMonitor::new(vec![], AlertPolicy::default());
+        assert_eq!(monitor.uptime_ratio("https://unknown.example").await, None);
+    }
+
+    #[tokio::test]
+    async fn status_prometheus_reports_per_site_gauges() {
+        let monitor = WebsiteMonitor::new(vec![], AlertPolicy::default());
+        let url = "https://www.example.com";
+        monitor.add_website(url.to_string()).await;
+        monitor.update_website_status().await;
+
+        let text = monitor.status_prometheus().await;
+        assert!(text.contains(&format!("pingu_up{{url=\"{url}\"}} 1")));
+        assert!(text.contains(&format!("pingu_total_checks{{url=\"{url}\"}} 1")));
+    }
+}
@@ -1,36 +1,85 @@
 use reqwest::Client;
 use std::collections::HashMap;
 use std::sync::Arc;
-use std::time::{Duration, SystemTime};
+use std::time::{Duration, Instant, SystemTime};
 use tokio::sync::RwLock;
+use tokio::task::JoinHandle;
 use tokio::time::interval;
 
-use crate::website::{Check, CheckStatus, ResponseDetails, Website};
-
-#[cfg(feature = "email_notifications")]
-use crate::email_config::EmailConfig;
-#[cfg(feature = "email_notifications")]
-use crate::website::FailReport;
-#[cfg(feature = "email_notifications")]
-use std::error::Error;
+use crate::alert_policy::AlertPolicy;
+use crate::notifier::Notifier;
+use crate::website::{
+    Check, CheckStatus, ExpectedStatus, FailReport, ResponseDetails, Severity, Website,
+    WebsiteOptions,
+};
 
 #[derive(Clone)]
 pub struct WebsiteMonitor {
     websites: Arc<RwLock<HashMap<String, Website>>>,
     client: Client,
-    #[cfg(feature = "email_notifications")]
-    email_config: EmailConfig,
+    notifiers: Arc<Vec<Box<dyn Notifier>>>,
+    alert_policy: AlertPolicy,
+    /// Polling task for each registered site, so it can be aborted when the
+    /// site is removed or respawned with a new interval. Empty until
+    /// `start_monitoring` has been called.
+    tasks: Arc<RwLock<HashMap<String, JoinHandle<()>>>>,
+    /// Fallback poll interval passed to `start_monitoring`. `None` means
+    /// monitoring hasn't started yet, so sites are tracked but not polled.
+    default_interval_secs: Arc<RwLock<Option<u64>>>,
 }
 
 impl WebsiteMonitor {
-    pub fn new(#[cfg(feature = "email_notifications")] email_config: EmailConfig) -> Self {
+    pub fn new(notifiers: Vec<Box<dyn Notifier>>, alert_policy: AlertPolicy) -> Self {
         WebsiteMonitor {
             websites: Arc::new(RwLock::new(HashMap::new())),
             client: Client::new(),
-            #[cfg(feature = "email_notifications")]
-            email_config,
+            notifiers: Arc::new(notifiers),
+            alert_policy,
+            tasks: Arc::new(RwLock::new(HashMap::new())),
+            default_interval_secs: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    async fn notify_all(&self, fail_report: &FailReport) {
+        for notifier in self.notifiers.iter() {
+            if let Err(err) = notifier.notify(fail_report).await {
+                eprintln!("failed to deliver notification: {err:?}");
+            }
         }
     }
+
+    /// (Re)spawns the polling task for `url`, aborting any task already
+    /// running for it first. A no-op until `start_monitoring` has set a
+    /// default interval - sites added before then are picked up once it's
+    /// called.
+    async fn spawn_task_for(&self, url: &str) {
+        if let Some(handle) = self.tasks.write().await.remove(url) {
+            handle.abort();
+        }
+
+        let Some(default_interval_secs) = *self.default_interval_secs.read().await else {
+            return;
+        };
+        let interval_secs = {
+            let websites = self.websites.read().await;
+            match websites.get(url) {
+                Some(website) => website.check_interval_secs.unwrap_or(default_interval_secs),
+                None => return,
+            }
+        };
+
+        let monitor = self.clone();
+        let task_url = url.to_string();
+        let spawned_url = url.to_string();
+        let handle = tokio::spawn(async move {
+            let mut interval = interval(Duration::from_secs(interval_secs));
+            loop {
+                interval.tick().await;
+                monitor.update_one(&spawned_url).await;
+            }
+        });
+        self.tasks.write().await.insert(task_url, handle);
+    }
 }
 
 impl WebsiteMonitor {
@@ -38,50 +87,94 @@ impl WebsiteMonitor {
         self.websites.read().await.clone()
     }
 
+    pub async fn remove_website(&self, url: &str) {
+        self.websites.write().await.remove(url);
+        if let Some(handle) = self.tasks.write().await.remove(url) {
+            handle.abort();
+        }
+    }
+
     pub async fn add_website(&self, url: String) {
-        let mut websites = self.websites.write().await;
-        websites.insert(
-            url.clone(),
-            Website {
-                url,
-                last_check: SystemTime::now(),
-                is_up: false,
-                total_checks: vec![],
-                successful_checks: 0,
-            },
-        );
+        self.add_website_with_options(url, WebsiteOptions::default())
+            .await;
+    }
+
+    pub async fn add_website_with_options(&self, url: String, options: WebsiteOptions) {
+        {
+            let mut websites = self.websites.write().await;
+            websites.insert(
+                url.clone(),
+                Website {
+                    url: url.clone(),
+                    last_check: SystemTime::now(),
+                    is_up: false,
+                    total_checks: vec![],
+                    successful_checks: 0,
+                    consecutive_failures: 0,
+                    last_notified: None,
+                    last_notified_severity: None,
+                    timeout_secs: options.timeout_secs,
+                    expected_status: options.expected_status,
+                    check_interval_secs: options.check_interval_secs,
+                    body_must_contain: options.body_must_contain,
+                },
+            );
+        }
+        self.spawn_task_for(&url).await;
+    }
+
+    pub async fn update_website_options(&self, url: &str, options: WebsiteOptions) {
+        {
+            let mut websites = self.websites.write().await;
+            if let Some(website) = websites.get_mut(url) {
+                website.timeout_secs = options.timeout_secs;
+                website.expected_status = options.expected_status;
+                website.check_interval_secs = options.check_interval_secs;
+                website.body_must_contain = options.body_must_contain;
+            }
+        }
+        self.spawn_task_for(url).await;
     }
 
     pub async fn check_website(&self, url: &str) -> CheckStatus {
+        let (timeout_secs, expected_status, body_must_contain) = {
+            let websites = self.websites.read().await;
+            match websites.get(url) {
+                Some(website) => (
+                    website.timeout_secs.unwrap_or(15),
+                    website.expected_status.clone().unwrap_or_default(),
+                    website.body_must_contain.clone(),
+                ),
+                None => (15, ExpectedStatus::default(), None),
+            }
+        };
+
+        let start = Instant::now();
         match self
             .client
             .get(url)
-            .timeout(Duration::from_secs(15))
+            .timeout(Duration::from_secs(timeout_secs))
             .send()
             .await
         {
             Ok(response) => {
-                if !response.status().is_success() {
-                    let status_code = response.status().as_u16();
-                    let error_message = response.text().await.unwrap();
-                    #[cfg(feature = "email_notifications")]
-                    {
-                        let fail_report = FailReport {
-                            url: url.to_string(),
-                            status_code,
-                            error_message: error_message.clone(),
-                            timestamp: SystemTime::now(),
-                        };
-                        self.send_email_notification(fail_report).await.unwrap();
-                    }
-                    CheckStatus::Down {
-                        status_code,
-                        error_message,
+                let status_code = response.status().as_u16();
+                let headers = response.headers().clone();
+                let content_length = response.content_length();
+                let body = response.text().await.unwrap_or_default();
+                let response_time = start.elapsed();
+
+                let mut failures = Vec::new();
+                if !expected_status.matches(status_code) {
+                    failures.push(format!("unexpected status code {status_code}"));
+                }
+                if let Some(needle) = &body_must_contain {
+                    if !body.contains(needle.as_str()) {
+                        failures.push(format!("response body did not contain {needle:?}"));
                     }
-                } else {
-                    let status_code = response.status().as_u16();
-                    let headers = response.headers().clone();
-                    let content_length = response.content_length();
+                }
+
+                if failures.is_empty() {
                     CheckStatus::Up(ResponseDetails {
                         status_code,
                         headers: headers
@@ -89,21 +182,17 @@ impl WebsiteMonitor {
                             .map(|(k, v)| (k.as_str().to_string(), v.to_str().unwrap().to_string()))
                             .collect(),
                         content_length,
+                        response_time,
                     })
+                } else {
+                    CheckStatus::Down {
+                        status_code,
+                        error_message: failures.join("; "),
+                    }
                 }
             }
             Err(err) => {
                 eprintln!("err = {:?}", err);
-                #[cfg(feature = "email_notifications")]
-                {
-                    let fail_report = FailReport {
-                        url: url.to_string(),
-                        status_code: u16::MAX,
-                        error_message: err.to_string(),
-                        timestamp: SystemTime::now(),
-                    };
-                    self.send_email_notification(fail_report).await.unwrap();
-                }
                 CheckStatus::Down {
                     status_code: 0,
                     error_message: err.to_string(),
@@ -112,18 +201,79 @@ impl WebsiteMonitor {
         }
     }
 
-    pub async fn update_website_status(&self) {
-        let mut websites = self.websites.write().await;
+    async fn update_one(&self, url: &str) {
+        let status = self.check_website(url).await;
+        let timestamp = SystemTime::now();
+
+        // Decide the state transition and whether to notify while holding
+        // the write lock, but drop the guard before the notify_all await -
+        // notifiers do real network I/O, and holding this lock across that
+        // would serialize every other site's checks and readers behind
+        // whichever site is currently sending an alert.
+        let fail_report = {
+            let mut websites = self.websites.write().await;
+            let Some(website) = websites.get_mut(url) else {
+                return;
+            };
 
-        for website in websites.values_mut() {
-            let status = self.check_website(&website.url).await;
-            let timestamp = SystemTime::now();
+            let was_up = website.is_up;
             website.last_check = timestamp;
             website.is_up = status.is_up();
-            if status.is_up() {
-                website.successful_checks += 1;
-            }
+
+            let fail_report = match &status {
+                CheckStatus::Up(_) => {
+                    website.successful_checks += 1;
+                    let fail_report =
+                        (!was_up && website.last_notified.is_some()).then(|| FailReport {
+                            url: website.url.clone(),
+                            status_code: 0,
+                            error_message: "recovered".to_string(),
+                            timestamp,
+                            severity: Severity::Warning,
+                        });
+                    website.consecutive_failures = 0;
+                    website.last_notified = None;
+                    website.last_notified_severity = None;
+                    fail_report
+                }
+                CheckStatus::Down {
+                    status_code,
+                    error_message,
+                } => {
+                    website.consecutive_failures += 1;
+                    let severity = self.alert_policy.decide_notification(
+                        website.consecutive_failures,
+                        website.last_notified,
+                        website.last_notified_severity,
+                        timestamp,
+                    );
+                    severity.map(|severity| {
+                        website.last_notified = Some(timestamp);
+                        website.last_notified_severity = Some(severity);
+                        FailReport {
+                            url: website.url.clone(),
+                            status_code: *status_code,
+                            error_message: error_message.clone(),
+                            timestamp,
+                            severity,
+                        }
+                    })
+                }
+            };
+
             website.total_checks.push(Check { status, timestamp });
+            fail_report
+        };
+
+        if let Some(fail_report) = fail_report {
+            self.notify_all(&fail_report).await;
+        }
+    }
+
+    pub async fn update_website_status(&self) {
+        let urls: Vec<String> = self.websites.read().await.keys().cloned().collect();
+        for url in urls {
+            self.update_one(&url).await;
         }
     }
 
@@ -137,67 +287,30 @@ impl WebsiteMonitor {
                 last_check: w.last_check,
                 total_checks: w.total_checks.clone(),
                 successful_checks: w.successful_checks,
+                consecutive_failures: w.consecutive_failures,
+                last_notified: w.last_notified,
+                last_notified_severity: w.last_notified_severity,
+                timeout_secs: w.timeout_secs,
+                expected_status: w.expected_status.clone(),
+                check_interval_secs: w.check_interval_secs,
+                body_must_contain: w.body_must_contain.clone(),
             })
             .collect()
     }
 
-    pub async fn start_monitoring(&self, interval_secs: u64) {
-        let monitor = self.clone();
-        tokio::spawn(async move {
-            let mut interval = interval(Duration::from_secs(interval_secs));
-            loop {
-                interval.tick().await;
-                monitor.update_website_status().await;
-            }
-        });
-    }
-
-    #[cfg(feature = "email_notifications")]
-    pub async fn send_email_notification(
-        &self,
-        fail_report: FailReport,
-    ) -> Result<(), Box<dyn Error>> {
-        use lettre::{
-            message::{header::ContentType, Mailbox},
-            transport::smtp::authentication::Credentials,
-            AsyncSmtpTransport, AsyncStd1Executor, AsyncTransport, Message,
-        };
+    /// Schedules every currently-registered website on its own tick, falling
+    /// back to `default_interval_secs` for sites without a
+    /// `check_interval_secs` override, so critical endpoints can be polled
+    /// more often than others. Sites added, removed, or re-configured after
+    /// this call (e.g. via a config reload) are picked up automatically,
+    /// since `add_website_with_options`, `remove_website`, and
+    /// `update_website_options` all (re)schedule their own task.
+    pub async fn start_monitoring(&self, default_interval_secs: u64) {
+        *self.default_interval_secs.write().await = Some(default_interval_secs);
 
-        let to_email: Mailbox = self.email_config.to_email.parse()?;
-
-        let email = Message::builder()
-            .from(self.email_config.from_email.clone().parse().unwrap())
-            .to(to_email)
-            .subject(&format!(
-                "{} {} is down!",
-                self.email_config.subject, fail_report.url
-            ))
-            .header(ContentType::TEXT_PLAIN)
-            .body(format!(
-                "The website {} is down with status code {}. Error message: {} At: {:?}",
-                fail_report.url,
-                fail_report.status_code,
-                fail_report.error_message,
-                fail_report.timestamp
-            ))?;
-
-        let creds = Credentials::new(
-            self.email_config.smtp_username.clone(),
-            self.email_config.smtp_password.clone(),
-        );
-
-        // Open a remote connection to gmail
-        let mailer: AsyncSmtpTransport<AsyncStd1Executor> =
-            AsyncSmtpTransport::<AsyncStd1Executor>::relay(&self.email_config.smtp_relay.clone())
-                .unwrap()
-                .credentials(creds)
-                .build();
-
-        match mailer.send(email).await {
-            Ok(_) => println!("Email sent successfully!"),
-            Err(e) => eprintln!("Could not send email: {e:?}"),
+        let urls: Vec<String> = self.websites.read().await.keys().cloned().collect();
+        for url in urls {
+            self.spawn_task_for(&url).await;
         }
-
-        Ok(())
     }
 }
@@ -0,0 +1,242 @@
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+
+use crate::website::{FailReport, Severity};
+
+/// A destination for down/recovery alerts. Implementations own their own
+/// transport (SMTP, HTTP, a third-party API, ...) and should treat a failed
+/// `notify` as non-fatal to the caller - the monitor keeps checking websites
+/// regardless of whether an alert made it out.
+#[async_trait::async_trait]
+pub trait Notifier: Send + Sync {
+    async fn notify(&self, fail_report: &FailReport) -> Result<(), Box<dyn Error>>;
+}
+
+/// Declarative description of a notifier, meant to be loaded from a config
+/// file. Untagged so a config can just list `{ smtp_relay = ..., ... }` or
+/// `{ url = ..., method = ... }` without an explicit `type` discriminator.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum NotifierConfig {
+    Email {
+        smtp_relay: String,
+        port: u16,
+        username: String,
+        password: String,
+        from: String,
+        to: String,
+        /// Recipient used instead of `to` once a failure escalates to
+        /// `Severity::Emergency`. Falls back to `to` when unset.
+        #[serde(default)]
+        emergency_to: Option<String>,
+        #[serde(default)]
+        tls_mode: TlsMode,
+        #[serde(default)]
+        auth_mechanism: AuthMechanism,
+    },
+    Webhook {
+        url: String,
+        method: String,
+    },
+    GitHubIssue {
+        token: String,
+        repo: String,
+    },
+}
+
+/// How to negotiate encryption with the SMTP relay.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+pub enum TlsMode {
+    /// Implicit TLS from the first byte, e.g. port 465.
+    Implicit,
+    /// Plaintext connection upgraded via STARTTLS, e.g. port 587.
+    StartTls,
+    /// No TLS at all - only appropriate for a trusted local relay.
+    None,
+}
+
+impl Default for TlsMode {
+    fn default() -> Self {
+        TlsMode::Implicit
+    }
+}
+
+/// Which SASL mechanism to authenticate with once connected.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+pub enum AuthMechanism {
+    Plain,
+    Login,
+}
+
+impl Default for AuthMechanism {
+    fn default() -> Self {
+        AuthMechanism::Plain
+    }
+}
+
+impl NotifierConfig {
+    pub fn build(self) -> Box<dyn Notifier> {
+        match self {
+            NotifierConfig::Email {
+                smtp_relay,
+                port,
+                username,
+                password,
+                from,
+                to,
+                emergency_to,
+                tls_mode,
+                auth_mechanism,
+            } => Box::new(EmailNotifier {
+                smtp_relay,
+                port,
+                username,
+                password,
+                from,
+                to,
+                emergency_to,
+                tls_mode,
+                auth_mechanism,
+            }),
+            NotifierConfig::Webhook { url, method } => Box::new(WebhookNotifier {
+                url,
+                method,
+                client: Client::new(),
+            }),
+            NotifierConfig::GitHubIssue { token, repo } => Box::new(GitHubIssueNotifier {
+                token,
+                repo,
+                client: Client::new(),
+            }),
+        }
+    }
+}
+
+pub struct EmailNotifier {
+    smtp_relay: String,
+    port: u16,
+    username: String,
+    password: String,
+    from: String,
+    to: String,
+    emergency_to: Option<String>,
+    tls_mode: TlsMode,
+    auth_mechanism: AuthMechanism,
+}
+
+#[async_trait::async_trait]
+impl Notifier for EmailNotifier {
+    async fn notify(&self, fail_report: &FailReport) -> Result<(), Box<dyn Error>> {
+        use lettre::{
+            message::{header::ContentType, Mailbox},
+            transport::smtp::authentication::{Credentials, Mechanism},
+            AsyncSmtpTransport, AsyncStd1Executor, AsyncTransport, Message,
+        };
+
+        let recipient = match fail_report.severity {
+            Severity::Emergency => self.emergency_to.as_deref().unwrap_or(&self.to),
+            Severity::Warning => &self.to,
+        };
+        let to_email: Mailbox = recipient.parse()?;
+
+        let subject = match fail_report.severity {
+            Severity::Emergency => format!("EMERGENCY: {} is down!", fail_report.url),
+            Severity::Warning => format!("{} is down!", fail_report.url),
+        };
+
+        let email = Message::builder()
+            .from(self.from.parse()?)
+            .to(to_email)
+            .subject(subject)
+            .header(ContentType::TEXT_PLAIN)
+            .body(format!(
+                "The website {} is down with status code {}. Error message: {} At: {:?}",
+                fail_report.url,
+                fail_report.status_code,
+                fail_report.error_message,
+                fail_report.timestamp
+            ))?;
+
+        let creds = Credentials::new(self.username.clone(), self.password.clone());
+        let mechanism = match self.auth_mechanism {
+            AuthMechanism::Plain => Mechanism::Plain,
+            AuthMechanism::Login => Mechanism::Login,
+        };
+
+        let mailer: AsyncSmtpTransport<AsyncStd1Executor> = match self.tls_mode {
+            TlsMode::Implicit => AsyncSmtpTransport::<AsyncStd1Executor>::relay(&self.smtp_relay)?
+                .port(self.port)
+                .credentials(creds)
+                .authentication(vec![mechanism])
+                .build(),
+            TlsMode::StartTls => {
+                AsyncSmtpTransport::<AsyncStd1Executor>::starttls_relay(&self.smtp_relay)?
+                    .port(self.port)
+                    .credentials(creds)
+                    .authentication(vec![mechanism])
+                    .build()
+            }
+            TlsMode::None => {
+                AsyncSmtpTransport::<AsyncStd1Executor>::builder_dangerous(&self.smtp_relay)
+                    .port(self.port)
+                    .credentials(creds)
+                    .authentication(vec![mechanism])
+                    .build()
+            }
+        };
+
+        match mailer.send(email).await {
+            Ok(_) => println!("Email sent successfully!"),
+            Err(e) => eprintln!("Could not send email: {e:?}"),
+        }
+
+        Ok(())
+    }
+}
+
+pub struct WebhookNotifier {
+    url: String,
+    method: String,
+    client: Client,
+}
+
+#[async_trait::async_trait]
+impl Notifier for WebhookNotifier {
+    async fn notify(&self, fail_report: &FailReport) -> Result<(), Box<dyn Error>> {
+        let method = reqwest::Method::from_bytes(self.method.as_bytes())?;
+        self.client
+            .request(method, &self.url)
+            .json(fail_report)
+            .send()
+            .await?;
+        Ok(())
+    }
+}
+
+pub struct GitHubIssueNotifier {
+    token: String,
+    repo: String,
+    client: Client,
+}
+
+#[async_trait::async_trait]
+impl Notifier for GitHubIssueNotifier {
+    async fn notify(&self, fail_report: &FailReport) -> Result<(), Box<dyn Error>> {
+        let url = format!("https://api.github.com/repos/{}/issues", self.repo);
+        self.client
+            .post(&url)
+            .bearer_auth(&self.token)
+            .header("User-Agent", "pingu")
+            .json(&serde_json::json!({
+                "title": format!("{} is down", fail_report.url),
+                "body": format!(
+                    "Status code: {}\nError message: {}\nAt: {:?}",
+                    fail_report.status_code, fail_report.error_message, fail_report.timestamp
+                ),
+            }))
+            .send()
+            .await?;
+        Ok(())
+    }
+}
@@ -1,12 +1,13 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime};
 
 #[derive(Clone, Serialize, Deserialize)]
 pub struct ResponseDetails {
     pub status_code: u16,
     pub headers: HashMap<String, String>,
     pub content_length: Option<u64>,
+    pub response_time: Duration,
 }
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -40,6 +41,93 @@ pub struct Website {
     pub is_up: bool,
     pub total_checks: Vec<Check>,
     pub successful_checks: u64,
+    /// Number of checks in a row that have come back down. Reset to zero as
+    /// soon as a check succeeds.
+    pub consecutive_failures: u32,
+    /// When the last alert was actually sent out, so repeat failures don't
+    /// re-notify until the resend period has elapsed.
+    pub last_notified: Option<SystemTime>,
+    /// Severity of the last alert actually sent out, so an escalation to
+    /// `Severity::Emergency` can bypass the resend throttle.
+    pub last_notified_severity: Option<Severity>,
+    /// Per-site request timeout. Falls back to a 15s default when unset.
+    pub timeout_secs: Option<u64>,
+    /// What counts as "up" for this site. Falls back to any 2xx when unset.
+    pub expected_status: Option<ExpectedStatus>,
+    /// How often this site should be polled by `start_monitoring`. Falls
+    /// back to the monitor-wide default interval when unset.
+    pub check_interval_secs: Option<u64>,
+    /// Substring that must appear in the response body for the site to be
+    /// considered up.
+    pub body_must_contain: Option<String>,
+}
+
+/// A single HTTP status code, or an inclusive range of them, that a check is
+/// expected to return.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum ExpectedStatus {
+    Exact(u16),
+    Range(u16, u16),
+}
+
+impl ExpectedStatus {
+    pub fn matches(&self, status_code: u16) -> bool {
+        match self {
+            ExpectedStatus::Exact(code) => status_code == *code,
+            ExpectedStatus::Range(low, high) => (*low..=*high).contains(&status_code),
+        }
+    }
+}
+
+impl Default for ExpectedStatus {
+    fn default() -> Self {
+        ExpectedStatus::Range(200, 299)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ExpectedStatus;
+
+    #[test]
+    fn exact_only_matches_that_code() {
+        let expected = ExpectedStatus::Exact(204);
+        assert!(expected.matches(204));
+        assert!(!expected.matches(200));
+    }
+
+    #[test]
+    fn range_matches_inclusive_bounds() {
+        let expected = ExpectedStatus::Range(200, 299);
+        assert!(expected.matches(200));
+        assert!(expected.matches(299));
+        assert!(!expected.matches(300));
+    }
+
+    #[test]
+    fn default_is_any_2xx() {
+        let expected = ExpectedStatus::default();
+        assert!(expected.matches(200));
+        assert!(!expected.matches(404));
+    }
+}
+
+/// Per-site overrides for [`Website::timeout_secs`] and friends, passed to
+/// `WebsiteMonitor::add_website_with_options`.
+#[derive(Debug, Clone, Default)]
+pub struct WebsiteOptions {
+    pub timeout_secs: Option<u64>,
+    pub expected_status: Option<ExpectedStatus>,
+    pub check_interval_secs: Option<u64>,
+    pub body_must_contain: Option<String>,
+}
+
+/// How urgently a failure should be treated. Notifiers may use this to pick
+/// a different recipient or escalation path for `Emergency` alerts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Severity {
+    Warning,
+    Emergency,
 }
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -48,4 +136,15 @@ pub struct FailReport {
     pub status_code: u16,
     pub error_message: String,
     pub timestamp: SystemTime,
+    pub severity: Severity,
+}
+
+/// A single recorded down check, as surfaced by
+/// `WebsiteMonitor::incidents_since`.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Incident {
+    pub url: String,
+    pub status_code: u16,
+    pub error_message: String,
+    pub timestamp: SystemTime,
 }